@@ -0,0 +1,250 @@
+//! An FFI-safe `Option` with a guaranteed `#[repr(C)]` layout, for crossing boundaries (C callers,
+//! BPF/program-style environments) where the layout of the standard library's `Option<T>` is not guaranteed.
+
+use std::future::Future;
+
+use crate::{AsyncOptionExt, ResultOptionExt};
+
+/// A `#[repr(C, u8)]`, FFI-safe equivalent of `Option<T>`.
+///
+/// The discriminant is pinned and tested to match common FFI conventions: `None` is tag `0`, `Some` is tag `1`.
+///
+/// # Example
+///
+/// ```rust
+/// use composable_utils::coption::COption;
+///
+/// let value: COption<u32> = Some(69).into();
+/// assert_eq!(value.unwrap_or(0), 69);
+/// ```
+#[repr(C, u8)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum COption<T> {
+	None = 0,
+	Some(T) = 1,
+}
+
+impl<T> COption<T> {
+	/// Returns `true` if the option is a `Some` value.
+	#[must_use]
+	pub const fn is_some(&self) -> bool {
+		matches!(self, Self::Some(_))
+	}
+
+	/// Returns `true` if the option is a `None` value.
+	#[must_use]
+	pub const fn is_none(&self) -> bool {
+		matches!(self, Self::None)
+	}
+
+	/// Returns a borrowing `Option<&T>` view of this `COption<T>`, without converting or consuming it.
+	#[must_use]
+	pub const fn as_option(&self) -> Option<&T> {
+		match self {
+			Self::Some(t) => Option::Some(t),
+			Self::None => Option::None,
+		}
+	}
+
+	/// Returns a mutably borrowing `Option<&mut T>` view of this `COption<T>`, without converting or consuming it.
+	pub const fn as_option_mut(&mut self) -> Option<&mut T> {
+		match self {
+			Self::Some(t) => Option::Some(t),
+			Self::None => Option::None,
+		}
+	}
+
+	/// Returns the contained `Some` value, consuming `self`.
+	///
+	/// # Panics
+	///
+	/// Panics if the value is `None`.
+	#[must_use]
+	pub fn unwrap(self) -> T {
+		match self {
+			Self::Some(t) => t,
+			Self::None => panic!("called `COption::unwrap()` on a `None` value"),
+		}
+	}
+
+	/// Returns the contained `Some` value or `default`.
+	pub fn unwrap_or(self, default: T) -> T {
+		match self {
+			Self::Some(t) => t,
+			Self::None => default,
+		}
+	}
+
+	/// Returns the contained `Some` value or computes it from `f`.
+	pub fn unwrap_or_else<F: FnOnce() -> T>(self, f: F) -> T {
+		match self {
+			Self::Some(t) => t,
+			Self::None => f(),
+		}
+	}
+
+	/// Maps a `COption<T>` to `COption<U>` by applying a function to a contained value (if `Some`) or returns `None` (if `None`).
+	pub fn map<U, F: FnOnce(T) -> U>(self, f: F) -> COption<U> {
+		match self {
+			Self::Some(t) => COption::Some(f(t)),
+			Self::None => COption::None,
+		}
+	}
+
+	/// Returns `None` if the option is `None`, otherwise calls `f` with the contained value and returns the result.
+	pub fn and_then<U, F: FnOnce(T) -> COption<U>>(self, f: F) -> COption<U> {
+		match self {
+			Self::Some(t) => f(t),
+			Self::None => COption::None,
+		}
+	}
+}
+
+impl<T> From<Option<T>> for COption<T> {
+	fn from(value: Option<T>) -> Self {
+		match value {
+			Option::Some(t) => Self::Some(t),
+			Option::None => Self::None,
+		}
+	}
+}
+
+/// Note: at a bare call site `Option::from(coption)` is ambiguous with std's blanket `impl<T> From<T> for Option<T>`
+/// (which would wrap the whole `COption<T>` as `Some(coption)`); annotate the target type, e.g. `Option::<u32>::from(coption)`.
+impl<T> From<COption<T>> for Option<T> {
+	fn from(value: COption<T>) -> Self {
+		match value {
+			COption::Some(t) => Self::Some(t),
+			COption::None => Self::None,
+		}
+	}
+}
+
+impl<T, E> ResultOptionExt<T, E> for COption<Result<T, E>> {
+	fn unwrap_or_err<E2>(self, err: E2) -> Result<T, E2> {
+		match self {
+			COption::Some(Ok(t)) => Ok(t),
+			COption::Some(Err(_)) | COption::None => Err(err),
+		}
+	}
+
+	fn unwrap_or_else_err<E2, F: FnOnce() -> E2>(self, f: F) -> Result<T, E2> {
+		match self {
+			COption::Some(Ok(t)) => Ok(t),
+			COption::Some(Err(_)) | COption::None => Err(f()),
+		}
+	}
+
+	fn unwrap_or_map_err<E2, F: FnOnce(E) -> E2>(self, default: E2, f: F) -> Result<T, E2> {
+		match self {
+			COption::Some(Ok(t)) => Ok(t),
+			COption::Some(Err(e)) => Err(f(e)),
+			COption::None => Err(default),
+		}
+	}
+
+	fn unwrap_or_else_map_err<E2, F: FnOnce(E) -> E2, F2: FnOnce() -> E2>(self, default: F2, f: F) -> Result<T, E2> {
+		match self {
+			COption::Some(Ok(t)) => Ok(t),
+			COption::Some(Err(e)) => Err(f(e)),
+			COption::None => Err(default()),
+		}
+	}
+
+	fn unwrap_or_err_chained<E2: From<E>>(self, default: E2) -> Result<T, E2> {
+		match self {
+			COption::Some(Ok(t)) => Ok(t),
+			COption::Some(Err(e)) => Err(E2::from(e)),
+			COption::None => Err(default),
+		}
+	}
+
+	fn unwrap_or_err_with_source<E2, F: FnOnce(Option<E>) -> E2>(self, f: F) -> Result<T, E2> {
+		match self {
+			COption::Some(Ok(t)) => Ok(t),
+			COption::Some(Err(e)) => Err(f(Some(e))),
+			COption::None => Err(f(None)),
+		}
+	}
+}
+
+impl<T, E> ResultOptionExt<T, E> for Result<COption<T>, E> {
+	fn unwrap_or_err<E2>(self, err: E2) -> Result<T, E2> {
+		match self {
+			Ok(COption::Some(t)) => Ok(t),
+			Ok(COption::None) | Err(_) => Err(err),
+		}
+	}
+
+	fn unwrap_or_else_err<E2, F: FnOnce() -> E2>(self, f: F) -> Result<T, E2> {
+		match self {
+			Ok(COption::Some(t)) => Ok(t),
+			Ok(COption::None) | Err(_) => Err(f()),
+		}
+	}
+
+	fn unwrap_or_map_err<E2, F: FnOnce(E) -> E2>(self, default: E2, f: F) -> Result<T, E2> {
+		match self {
+			Ok(COption::Some(t)) => Ok(t),
+			Ok(COption::None) => Err(default),
+			Err(e) => Err(f(e)),
+		}
+	}
+
+	fn unwrap_or_else_map_err<E2, F: FnOnce(E) -> E2, F2: FnOnce() -> E2>(self, default: F2, f: F) -> Result<T, E2> {
+		match self {
+			Ok(COption::Some(t)) => Ok(t),
+			Ok(COption::None) => Err(default()),
+			Err(e) => Err(f(e)),
+		}
+	}
+
+	fn unwrap_or_err_chained<E2: From<E>>(self, default: E2) -> Result<T, E2> {
+		match self {
+			Ok(COption::Some(t)) => Ok(t),
+			Ok(COption::None) => Err(default),
+			Err(e) => Err(E2::from(e)),
+		}
+	}
+
+	fn unwrap_or_err_with_source<E2, F: FnOnce(Option<E>) -> E2>(self, f: F) -> Result<T, E2> {
+		match self {
+			Ok(COption::Some(t)) => Ok(t),
+			Ok(COption::None) => Err(f(None)),
+			Err(e) => Err(f(Some(e))),
+		}
+	}
+}
+
+#[allow(async_fn_in_trait)]
+impl<T> AsyncOptionExt<T> for COption<T> {
+	async fn async_map<U, Fut: Future<Output = U>, F: FnOnce(T) -> Fut>(self, f: F) -> Option<U> {
+		Option::from(self).async_map(f).await
+	}
+
+	async fn async_and_then<U, Fut: Future<Output = Option<U>>, F: FnOnce(T) -> Fut>(self, f: F) -> Option<U> {
+		Option::from(self).async_and_then(f).await
+	}
+
+	async fn async_filter<Fut: Future<Output = bool>, F: FnOnce(T) -> Fut>(self, predicate: F) -> Option<T>
+	where
+		T: Clone,
+	{
+		Option::from(self).async_filter(predicate).await
+	}
+
+	async fn async_or_else<Fut: Future<Output = Option<T>>, F: FnOnce() -> Fut>(self, f: F) -> Option<T> {
+		Option::from(self).async_or_else(f).await
+	}
+
+	async fn async_unwrap_or_else<Fut: Future<Output = T>, F: FnOnce() -> Fut>(self, f: F) -> T {
+		Option::from(self).async_unwrap_or_else(f).await
+	}
+
+	async fn async_inspect<Fut: Future<Output = ()>, F: FnOnce(T) -> Fut>(self, f: F) -> Self
+	where
+		T: Clone,
+	{
+		Option::from(self).async_inspect(f).await.into()
+	}
+}
@@ -3,6 +3,8 @@
 
 use std::future::Future;
 
+pub mod coption;
+
 #[allow(async_fn_in_trait)]
 pub trait AsyncOptionExt<T> {
 	/// Maps an `Option<T>` to `Option<U>` by applying a function to a contained value (if `Some`) or returns `None` (if `None`).
@@ -26,6 +28,105 @@ pub trait AsyncOptionExt<T> {
 	/// });
 	/// ```
 	async fn async_map<U, Fut: Future<Output = U>, F: FnOnce(T) -> Fut>(self, f: F) -> Option<U>;
+
+	/// Returns `None` if the option is `None`, otherwise calls `f` with the contained value and returns the resulting `Option<U>`.
+	///
+	/// The future returned by `f` is only constructed and awaited when `self` is `Some`.
+	///
+	/// # Example
+	///
+	/// ```rust
+	/// use composable_utils::AsyncOptionExt;
+	///
+	/// async fn half_if_even(x: usize) -> Option<usize> {
+	///     (x % 2 == 0).then_some(x / 2)
+	/// }
+	///
+	/// async_io::block_on(async {
+	///     let value = Some(4).async_and_then(|v| async move { half_if_even(v).await }).await;
+	///     assert_eq!(value, Some(2));
+	/// });
+	/// ```
+	async fn async_and_then<U, Fut: Future<Output = Option<U>>, F: FnOnce(T) -> Fut>(self, f: F) -> Option<U>;
+
+	/// Returns `None` if the option is `None`, otherwise calls `predicate` with a clone of the contained value and returns
+	/// `Some(t)` if the predicate future resolves to `true`, or `None` otherwise.
+	///
+	/// `predicate` takes the value by owned clone rather than by reference, since a borrow can't be threaded through an
+	/// `.await` point without also naming its lifetime on `Fut`.
+	///
+	/// The predicate future is only constructed and awaited when `self` is `Some`.
+	///
+	/// # Example
+	///
+	/// ```rust
+	/// use composable_utils::AsyncOptionExt;
+	///
+	/// async fn is_even(x: usize) -> bool {
+	///     x % 2 == 0
+	/// }
+	///
+	/// async_io::block_on(async {
+	///     let value = Some(4).async_filter(|v| async move { is_even(v).await }).await;
+	///     assert_eq!(value, Some(4));
+	/// });
+	/// ```
+	async fn async_filter<Fut: Future<Output = bool>, F: FnOnce(T) -> Fut>(self, predicate: F) -> Option<T>
+	where
+		T: Clone;
+
+	/// Returns the option if it contains a value, otherwise calls `f` and returns the resulting `Option<T>`.
+	///
+	/// The future returned by `f` is only constructed and awaited when `self` is `None`.
+	///
+	/// # Example
+	///
+	/// ```rust
+	/// use composable_utils::AsyncOptionExt;
+	///
+	/// async_io::block_on(async {
+	///     let value = None.async_or_else(|| async { Some(69) }).await;
+	///     assert_eq!(value, Some(69));
+	/// });
+	/// ```
+	async fn async_or_else<Fut: Future<Output = Option<T>>, F: FnOnce() -> Fut>(self, f: F) -> Option<T>;
+
+	/// Returns the contained `Some` value, or computes it from `f`.
+	///
+	/// The future returned by `f` is only constructed and awaited when `self` is `None`.
+	///
+	/// # Example
+	///
+	/// ```rust
+	/// use composable_utils::AsyncOptionExt;
+	///
+	/// async_io::block_on(async {
+	///     let value = None.async_unwrap_or_else(|| async { 69 }).await;
+	///     assert_eq!(value, 69);
+	/// });
+	/// ```
+	async fn async_unwrap_or_else<Fut: Future<Output = T>, F: FnOnce() -> Fut>(self, f: F) -> T;
+
+	/// Calls `f` with a clone of the contained value (if `Some`), awaiting the resulting future, then returns `self` unchanged.
+	///
+	/// `f` takes the value by owned clone rather than by reference, since a borrow can't be threaded through an `.await`
+	/// point without also naming its lifetime on `Fut`.
+	///
+	/// The future returned by `f` is only constructed and awaited when `self` is `Some`.
+	///
+	/// # Example
+	///
+	/// ```rust
+	/// use composable_utils::AsyncOptionExt;
+	///
+	/// async_io::block_on(async {
+	///     let value = Some(69).async_inspect(|v| async move { println!("got {v}") }).await;
+	///     assert_eq!(value, Some(69));
+	/// });
+	/// ```
+	async fn async_inspect<Fut: Future<Output = ()>, F: FnOnce(T) -> Fut>(self, f: F) -> Self
+	where
+		T: Clone;
 }
 
 pub trait ResultOptionExt<T, E> {
@@ -162,6 +263,282 @@ pub trait ResultOptionExt<T, E> {
 	///	assert!(matches!(option_some_err().unwrap_or_else_map_err(|| ErrorTwo::Two, |err| ErrorTwo::Three), Err(ErrorTwo::Three)));
 	/// ```
 	fn unwrap_or_else_map_err<E2, F: FnOnce(E) -> E2, F2: FnOnce() -> E2>(self, default: F2, f: F) -> Result<T, E2>;
+
+	/// Maps either a `Result<Option<T>, E>` or `Option<Result<T, E>>` to a `Result<T, E2>`, converting a contained `Err` value
+	/// into `E2` via `From` (so it can be kept as a `source` in an `std::error::Error` chain rather than discarded).
+	/// Defaults to `Err(default)` if `None`.
+	///
+	/// If it's a Result:
+	///    - Ok(Some(t)) -> Ok(t)
+	///    - Ok(None) -> Err(default)
+	///    - Err(e) -> Err(E2::from(e))
+	///
+	/// If it's an Option:
+	///    - Some(Ok(t)) -> Ok(t)
+	///    - Some(Err(e)) -> Err(E2::from(e))
+	///    - None -> Err(default)
+	///
+	/// # Example
+	///
+	/// ```rust
+	///	use composable_utils::ResultOptionExt;
+	///
+	/// enum ErrorOne {
+	///	   One,
+	/// }
+	///
+	/// enum ErrorTwo {
+	///	   Two,
+	///    One(ErrorOne),
+	/// }
+	///
+	/// impl From<ErrorOne> for ErrorTwo {
+	///    fn from(err: ErrorOne) -> Self {
+	///        ErrorTwo::One(err)
+	///    }
+	/// }
+	///
+	/// fn result_ok_none() -> Result<Option<&'static str>, ErrorOne> {
+	///    Ok(None)
+	/// }
+	///
+	/// fn option_some_err() -> Option<Result<&'static str, ErrorOne>> {
+	///    Some(Err(ErrorOne::One))
+	/// }
+	///
+	/// assert!(matches!(result_ok_none().unwrap_or_err_chained(ErrorTwo::Two), Err(ErrorTwo::Two)));
+	///	assert!(matches!(option_some_err().unwrap_or_err_chained(ErrorTwo::Two), Err(ErrorTwo::One(ErrorOne::One))));
+	/// ```
+	fn unwrap_or_err_chained<E2: From<E>>(self, default: E2) -> Result<T, E2>;
+
+	/// Maps either a `Result<Option<T>, E>` or `Option<Result<T, E>>` to a `Result<T, E2>` by calling `f` with `Some(e)` when a
+	/// contained `Err(e)` existed, or `None` when the value was merely absent, so `f` can distinguish the two causes and build
+	/// an `E2` that keeps the original error as a source instead of collapsing both cases into the same `E2`.
+	///
+	/// If it's a Result:
+	///    - Ok(Some(t)) -> Ok(t)
+	///    - Ok(None) -> Err(f(None))
+	///    - Err(e) -> Err(f(Some(e)))
+	///
+	/// If it's an Option:
+	///    - Some(Ok(t)) -> Ok(t)
+	///    - Some(Err(e)) -> Err(f(Some(e)))
+	///    - None -> Err(f(None))
+	///
+	/// # Example
+	///
+	/// ```rust
+	///	use composable_utils::ResultOptionExt;
+	///
+	/// enum ErrorOne {
+	///	   One,
+	/// }
+	///
+	/// #[derive(Debug)]
+	/// enum ErrorTwo {
+	///    WasAbsent,
+	///    WasErr,
+	/// }
+	///
+	/// fn result_ok_none() -> Result<Option<&'static str>, ErrorOne> {
+	///    Ok(None)
+	/// }
+	///
+	/// fn option_some_err() -> Option<Result<&'static str, ErrorOne>> {
+	///    Some(Err(ErrorOne::One))
+	/// }
+	///
+	/// assert!(matches!(
+	///     result_ok_none().unwrap_or_err_with_source(|source| if source.is_some() { ErrorTwo::WasErr } else { ErrorTwo::WasAbsent }),
+	///     Err(ErrorTwo::WasAbsent)
+	/// ));
+	///	assert!(matches!(
+	///     option_some_err().unwrap_or_err_with_source(|source| if source.is_some() { ErrorTwo::WasErr } else { ErrorTwo::WasAbsent }),
+	///     Err(ErrorTwo::WasErr)
+	/// ));
+	/// ```
+	fn unwrap_or_err_with_source<E2, F: FnOnce(Option<E>) -> E2>(self, f: F) -> Result<T, E2>;
+}
+
+#[allow(async_fn_in_trait)]
+pub trait AsyncResultOptionExt<T, E> {
+	/// Async counterpart to [`ResultOptionExt::unwrap_or_else_err`] for when computing the fallback error requires an `.await`.
+	///
+	/// The future returned by `f` is only constructed and awaited on the `None`/absent and `Err(_)` branches; it is never touched on
+	/// `Ok(Some(_))`.
+	///
+	/// # Example
+	///
+	/// ```rust
+	///	use composable_utils::AsyncResultOptionExt;
+	///
+	/// enum ErrorOne {
+	///	   One,
+	/// }
+	///
+	/// enum ErrorTwo {
+	///	   Two,
+	/// }
+	///
+	/// fn result_ok_none() -> Result<Option<&'static str>, ErrorOne> {
+	///    Ok(None)
+	/// }
+	///
+	/// async_io::block_on(async {
+	///     assert!(matches!(
+	///         result_ok_none().unwrap_or_else_err(|| async { ErrorTwo::Two }).await,
+	///         Err(ErrorTwo::Two)
+	///     ));
+	/// });
+	/// ```
+	async fn unwrap_or_else_err<E2, Fut: Future<Output = E2>, F: FnOnce() -> Fut>(self, f: F) -> Result<T, E2>;
+
+	/// Async counterpart to [`ResultOptionExt::unwrap_or_map_err`] for when mapping the inner error requires an `.await`.
+	/// Defaults to `Err(default)` if `None`, same as the sync version.
+	///
+	/// The future returned by `f` is only constructed and awaited on the `Err(_)` branch.
+	///
+	/// # Example
+	///
+	/// ```rust
+	///	use composable_utils::AsyncResultOptionExt;
+	///
+	/// enum ErrorOne {
+	///	   One,
+	/// }
+	///
+	/// enum ErrorTwo {
+	///	   Two,
+	///    Three,
+	/// }
+	///
+	/// fn option_some_err() -> Option<Result<&'static str, ErrorOne>> {
+	///    Some(Err(ErrorOne::One))
+	/// }
+	///
+	/// async_io::block_on(async {
+	///     assert!(matches!(
+	///         option_some_err().unwrap_or_map_err(ErrorTwo::Two, |_| async { ErrorTwo::Three }).await,
+	///         Err(ErrorTwo::Three)
+	///     ));
+	/// });
+	/// ```
+	async fn unwrap_or_map_err<E2, Fut: Future<Output = E2>, F: FnOnce(E) -> Fut>(self, default: E2, f: F) -> Result<T, E2>;
+
+	/// Async counterpart to [`ResultOptionExt::unwrap_or_else_map_err`] for when both the fallback error and the error mapping require an `.await`.
+	///
+	/// `default`'s future is only constructed/awaited on the `None`/absent branch, and `f`'s future only on the `Err(_)` branch.
+	///
+	/// # Example
+	///
+	/// ```rust
+	///	use composable_utils::AsyncResultOptionExt;
+	///
+	/// enum ErrorOne {
+	///	   One,
+	/// }
+	///
+	/// enum ErrorTwo {
+	///	   Two,
+	///    Three,
+	/// }
+	///
+	/// fn result_ok_none() -> Result<Option<&'static str>, ErrorOne> {
+	///    Ok(None)
+	/// }
+	///
+	/// async_io::block_on(async {
+	///     assert!(matches!(
+	///         result_ok_none()
+	///             .unwrap_or_else_map_err(|| async { ErrorTwo::Two }, |_| async { ErrorTwo::Three })
+	///             .await,
+	///         Err(ErrorTwo::Two)
+	///     ));
+	/// });
+	/// ```
+	async fn unwrap_or_else_map_err<
+		E2,
+		Fut: Future<Output = E2>,
+		F: FnOnce(E) -> Fut,
+		Fut2: Future<Output = E2>,
+		F2: FnOnce() -> Fut2,
+	>(
+		self,
+		default: F2,
+		f: F,
+	) -> Result<T, E2>;
+}
+
+impl<T, E> AsyncResultOptionExt<T, E> for Option<Result<T, E>> {
+	async fn unwrap_or_else_err<E2, Fut: Future<Output = E2>, F: FnOnce() -> Fut>(self, f: F) -> Result<T, E2> {
+		match self {
+			Some(Ok(t)) => Ok(t),
+			Some(Err(_)) => Err(f().await),
+			None => Err(f().await),
+		}
+	}
+
+	async fn unwrap_or_map_err<E2, Fut: Future<Output = E2>, F: FnOnce(E) -> Fut>(self, default: E2, f: F) -> Result<T, E2> {
+		match self {
+			Some(Ok(t)) => Ok(t),
+			Some(Err(e)) => Err(f(e).await),
+			None => Err(default),
+		}
+	}
+
+	async fn unwrap_or_else_map_err<
+		E2,
+		Fut: Future<Output = E2>,
+		F: FnOnce(E) -> Fut,
+		Fut2: Future<Output = E2>,
+		F2: FnOnce() -> Fut2,
+	>(
+		self,
+		default: F2,
+		f: F,
+	) -> Result<T, E2> {
+		match self {
+			Some(Ok(t)) => Ok(t),
+			Some(Err(e)) => Err(f(e).await),
+			None => Err(default().await),
+		}
+	}
+}
+
+impl<T, E> AsyncResultOptionExt<T, E> for Result<Option<T>, E> {
+	async fn unwrap_or_else_err<E2, Fut: Future<Output = E2>, F: FnOnce() -> Fut>(self, f: F) -> Result<T, E2> {
+		match self {
+			Ok(Some(t)) => Ok(t),
+			Ok(None) => Err(f().await),
+			Err(_) => Err(f().await),
+		}
+	}
+
+	async fn unwrap_or_map_err<E2, Fut: Future<Output = E2>, F: FnOnce(E) -> Fut>(self, default: E2, f: F) -> Result<T, E2> {
+		match self {
+			Ok(Some(t)) => Ok(t),
+			Ok(None) => Err(default),
+			Err(e) => Err(f(e).await),
+		}
+	}
+
+	async fn unwrap_or_else_map_err<
+		E2,
+		Fut: Future<Output = E2>,
+		F: FnOnce(E) -> Fut,
+		Fut2: Future<Output = E2>,
+		F2: FnOnce() -> Fut2,
+	>(
+		self,
+		default: F2,
+		f: F,
+	) -> Result<T, E2> {
+		match self {
+			Ok(Some(t)) => Ok(t),
+			Ok(None) => Err(default().await),
+			Err(e) => Err(f(e).await),
+		}
+	}
 }
 
 impl<T> AsyncOptionExt<T> for Option<T> {
@@ -171,6 +548,53 @@ impl<T> AsyncOptionExt<T> for Option<T> {
 			None => None,
 		}
 	}
+
+	async fn async_and_then<U, Fut: Future<Output = Option<U>>, F: FnOnce(T) -> Fut>(self, f: F) -> Option<U> {
+		match self {
+			Some(t) => f(t).await,
+			None => None,
+		}
+	}
+
+	async fn async_filter<Fut: Future<Output = bool>, F: FnOnce(T) -> Fut>(self, predicate: F) -> Option<T>
+	where
+		T: Clone,
+	{
+		match self {
+			Some(t) => {
+				if predicate(t.clone()).await {
+					Some(t)
+				} else {
+					None
+				}
+			}
+			None => None,
+		}
+	}
+
+	async fn async_or_else<Fut: Future<Output = Option<T>>, F: FnOnce() -> Fut>(self, f: F) -> Option<T> {
+		match self {
+			Some(t) => Some(t),
+			None => f().await,
+		}
+	}
+
+	async fn async_unwrap_or_else<Fut: Future<Output = T>, F: FnOnce() -> Fut>(self, f: F) -> T {
+		match self {
+			Some(t) => t,
+			None => f().await,
+		}
+	}
+
+	async fn async_inspect<Fut: Future<Output = ()>, F: FnOnce(T) -> Fut>(self, f: F) -> Self
+	where
+		T: Clone,
+	{
+		if let Some(t) = &self {
+			f(t.clone()).await;
+		}
+		self
+	}
 }
 
 impl<T, E> ResultOptionExt<T, E> for Option<Result<T, E>> {
@@ -205,6 +629,22 @@ impl<T, E> ResultOptionExt<T, E> for Option<Result<T, E>> {
 			None => Err(default()),
 		}
 	}
+
+	fn unwrap_or_err_chained<E2: From<E>>(self, default: E2) -> Result<T, E2> {
+		match self {
+			Some(Ok(t)) => Ok(t),
+			Some(Err(e)) => Err(E2::from(e)),
+			None => Err(default),
+		}
+	}
+
+	fn unwrap_or_err_with_source<E2, F: FnOnce(Option<E>) -> E2>(self, f: F) -> Result<T, E2> {
+		match self {
+			Some(Ok(t)) => Ok(t),
+			Some(Err(e)) => Err(f(Some(e))),
+			None => Err(f(None)),
+		}
+	}
 }
 
 impl<T, E> ResultOptionExt<T, E> for Result<Option<T>, E> {
@@ -247,4 +687,124 @@ impl<T, E> ResultOptionExt<T, E> for Result<Option<T>, E> {
 			Err(e) => Err(f(e)),
 		}
 	}
+
+	fn unwrap_or_err_chained<E2: From<E>>(self, default: E2) -> Result<T, E2> {
+		match self {
+			Ok(t) => match t {
+				Some(t) => Ok(t),
+				None => Err(default),
+			},
+			Err(e) => Err(E2::from(e)),
+		}
+	}
+
+	fn unwrap_or_err_with_source<E2, F: FnOnce(Option<E>) -> E2>(self, f: F) -> Result<T, E2> {
+		match self {
+			Ok(t) => match t {
+				Some(t) => Ok(t),
+				None => Err(f(None)),
+			},
+			Err(e) => Err(f(Some(e))),
+		}
+	}
+}
+
+/// Converts between the two shapes `ResultOptionExt` already unifies, the same way `Option::transpose`/`Result::transpose` do.
+pub trait TransposeExt<T, E> {
+	/// The other of the two shapes this type transposes into.
+	type Transposed;
+
+	/// Transposes `Result<Option<T>, E>` into `Option<Result<T, E>>`, or vice-versa.
+	///
+	///    - `Ok(Some(t))` <-> `Some(Ok(t))`
+	///    - `Ok(None)` <-> `None`
+	///    - `Err(e)` <-> `Some(Err(e))`
+	///
+	/// # Example
+	///
+	/// ```rust
+	///	use composable_utils::TransposeExt;
+	///
+	/// let result: Result<Option<&str>, &str> = Ok(Some("trans rights"));
+	/// assert_eq!(TransposeExt::transpose(result), Some(Ok("trans rights")));
+	///
+	/// let option: Option<Result<&str, &str>> = None;
+	/// assert_eq!(TransposeExt::transpose(option), Ok(None));
+	/// ```
+	fn transpose(self) -> Self::Transposed;
+}
+
+impl<T, E> TransposeExt<T, E> for Result<Option<T>, E> {
+	type Transposed = Option<Result<T, E>>;
+
+	fn transpose(self) -> Self::Transposed {
+		match self {
+			Ok(Some(t)) => Some(Ok(t)),
+			Ok(None) => None,
+			Err(e) => Some(Err(e)),
+		}
+	}
+}
+
+impl<T, E> TransposeExt<T, E> for Option<Result<T, E>> {
+	type Transposed = Result<Option<T>, E>;
+
+	fn transpose(self) -> Self::Transposed {
+		match self {
+			Some(Ok(t)) => Ok(Some(t)),
+			Some(Err(e)) => Err(e),
+			None => Ok(None),
+		}
+	}
+}
+
+/// Collapses a nested `Option<Option<T>>` into `Option<T>`, the way `Option::flatten` does before it was stabilized as a method on
+/// `Option` itself.
+pub trait FlattenNoneExt<T> {
+	/// Collapses `Some(Some(t))` into `Some(t)`, and both `Some(None)` and `None` into `None`.
+	///
+	/// # Example
+	///
+	/// ```rust
+	///	use composable_utils::FlattenNoneExt;
+	///
+	/// assert_eq!(Some(Some("trans rights")).flatten_none(), Some("trans rights"));
+	/// assert_eq!(Some(None::<&str>).flatten_none(), None);
+	/// assert_eq!(None::<Option<&str>>.flatten_none(), None);
+	/// ```
+	fn flatten_none(self) -> Option<T>;
+}
+
+impl<T> FlattenNoneExt<T> for Option<Option<T>> {
+	fn flatten_none(self) -> Option<T> {
+		match self {
+			Some(Some(t)) => Some(t),
+			Some(None) | None => None,
+		}
+	}
+}
+
+/// Collects an iterator of `Option<Result<T, E>>` the way `Iterator::collect::<Result<Vec<T>, E>>()` collects an iterator of
+/// `Result<T, E>`, skipping `None` items rather than treating them as an error.
+pub trait CollectResultExt<T, E> {
+	/// Collects `Some(Ok(t))` items into the output `Vec`, skips `None` items, and short-circuits on the first `Some(Err(e))`.
+	///
+	/// # Example
+	///
+	/// ```rust
+	///	use composable_utils::CollectResultExt;
+	///
+	/// let items: Vec<Option<Result<u32, &str>>> = vec![Some(Ok(1)), None, Some(Ok(2))];
+	/// assert_eq!(items.into_iter().collect_result(), Ok(vec![1, 2]));
+	///
+	/// let items: Vec<Option<Result<u32, &str>>> = vec![Some(Ok(1)), Some(Err("oh no")), Some(Ok(2))];
+	/// assert_eq!(items.into_iter().collect_result(), Err("oh no"));
+	/// ```
+	fn collect_result(self) -> Result<Vec<T>, E>;
+}
+
+impl<T, E, I: Iterator<Item = Option<Result<T, E>>>> CollectResultExt<T, E> for I {
+	fn collect_result(self) -> Result<Vec<T>, E> {
+		self.flatten().collect()
+	}
 }
@@ -4,6 +4,14 @@ async fn double(value: usize) -> usize {
 	value * 2
 }
 
+async fn half_if_even(value: usize) -> Option<usize> {
+	(value % 2 == 0).then_some(value / 2)
+}
+
+async fn is_even(value: usize) -> bool {
+	value % 2 == 0
+}
+
 #[test]
 fn async_map() {
 	async_io::block_on(async {
@@ -15,3 +23,51 @@ fn async_map() {
 		assert_eq!(value, 138);
 	});
 }
+
+#[test]
+fn async_and_then() {
+	async_io::block_on(async {
+		assert_eq!(Some(4).async_and_then(|v| async move { half_if_even(v).await }).await, Some(2));
+		assert_eq!(Some(3).async_and_then(|v| async move { half_if_even(v).await }).await, None);
+		assert_eq!(None.async_and_then(|v| async move { half_if_even(v).await }).await, None);
+	});
+}
+
+#[test]
+fn async_filter() {
+	async_io::block_on(async {
+		assert_eq!(Some(4).async_filter(|v| async move { is_even(v).await }).await, Some(4));
+		assert_eq!(Some(3).async_filter(|v| async move { is_even(v).await }).await, None);
+		assert_eq!(None.async_filter(|v| async move { is_even(v).await }).await, None);
+	});
+}
+
+#[test]
+fn async_or_else() {
+	async_io::block_on(async {
+		assert_eq!(Some(69).async_or_else(|| async { Some(0) }).await, Some(69));
+		assert_eq!(None.async_or_else(|| async { Some(69) }).await, Some(69));
+	});
+}
+
+#[test]
+fn async_unwrap_or_else() {
+	async_io::block_on(async {
+		assert_eq!(Some(69).async_unwrap_or_else(|| async { 0 }).await, 69);
+		assert_eq!(None.async_unwrap_or_else(|| async { 69 }).await, 69);
+	});
+}
+
+#[test]
+fn async_inspect() {
+	async_io::block_on(async {
+		let mut calls = 0;
+		let value = Some(69).async_inspect(|_| async { calls += 1 }).await;
+		assert_eq!(value, Some(69));
+		assert_eq!(calls, 1);
+
+		let value: Option<usize> = None.async_inspect(|_| async { calls += 1 }).await;
+		assert_eq!(value, None);
+		assert_eq!(calls, 1);
+	});
+}
@@ -0,0 +1,189 @@
+use composable_utils::coption::COption;
+use composable_utils::{AsyncOptionExt, ResultOptionExt};
+
+enum ErrorOne {
+	One,
+}
+
+enum ErrorTwo {
+	Two,
+	Three,
+	One(ErrorOne),
+}
+
+impl From<ErrorOne> for ErrorTwo {
+	fn from(err: ErrorOne) -> Self {
+		ErrorTwo::One(err)
+	}
+}
+
+/// Reads the raw `#[repr(C, u8)]` tag byte, the way an FFI caller on the other side of the boundary would.
+fn tag<T>(value: &COption<T>) -> u8 {
+	// SAFETY: `COption<T>` is `#[repr(C, u8)]`, so its first byte is always the discriminant.
+	unsafe { *std::ptr::from_ref(value).cast::<u8>() }
+}
+
+#[test]
+fn discriminant_matches_common_ffi_convention() {
+	assert_eq!(tag(&COption::<u32>::None), 0);
+	assert_eq!(tag(&COption::Some(69u32)), 1);
+}
+
+#[test]
+fn from_and_into_std_option() {
+	let some: COption<u32> = Some(69).into();
+	let none: COption<u32> = None.into();
+	assert_eq!(some, COption::Some(69));
+	assert_eq!(none, COption::None);
+
+	assert_eq!(Option::<u32>::from(some), Some(69));
+	assert_eq!(Option::<u32>::from(none), None);
+}
+
+#[test]
+fn as_option_views() {
+	let mut value = COption::Some(69);
+	assert_eq!(value.as_option(), Some(&69));
+	*value.as_option_mut().unwrap() = 70;
+	assert_eq!(value, COption::Some(70));
+
+	let mut none: COption<u32> = COption::None;
+	assert_eq!(none.as_option(), None);
+	assert_eq!(none.as_option_mut(), None);
+}
+
+#[test]
+fn query_and_extract() {
+	assert!(COption::Some(69).is_some());
+	assert!(!COption::Some(69).is_none());
+	assert!(COption::<u32>::None.is_none());
+	assert!(!COption::<u32>::None.is_some());
+
+	assert_eq!(COption::Some(69).unwrap(), 69);
+	assert_eq!(COption::Some(69).unwrap_or(0), 69);
+	assert_eq!(COption::<u32>::None.unwrap_or(0), 0);
+	assert_eq!(COption::Some(69).unwrap_or_else(|| 0), 69);
+	assert_eq!(COption::<u32>::None.unwrap_or_else(|| 69), 69);
+}
+
+#[test]
+#[should_panic(expected = "called `COption::unwrap()` on a `None` value")]
+fn unwrap_none_panics() {
+	let _ = COption::<u32>::None.unwrap();
+}
+
+#[test]
+fn map_and_and_then() {
+	assert_eq!(COption::Some(69).map(|v| v * 2), COption::Some(138));
+	assert_eq!(COption::<u32>::None.map(|v| v * 2), COption::None);
+
+	assert_eq!(COption::Some(4).and_then(|v| COption::Some(v / 2)), COption::Some(2));
+	assert_eq!(COption::<u32>::None.and_then(|v| COption::Some(v / 2)), COption::None);
+}
+
+#[test]
+fn result_option_ext_over_coption_result() {
+	let ok_some: COption<Result<&'static str, ErrorOne>> = COption::Some(Ok("trans rights"));
+	let ok_none: COption<Result<&'static str, ErrorOne>> = COption::None;
+	let err: COption<Result<&'static str, ErrorOne>> = COption::Some(Err(ErrorOne::One));
+
+	assert!(ok_some.unwrap_or_err(ErrorTwo::Two).is_ok());
+	assert!(ok_none.unwrap_or_err(ErrorTwo::Two).is_err());
+	assert!(err.unwrap_or_err(ErrorTwo::Two).is_err());
+}
+
+#[test]
+fn result_option_ext_over_coption_result_with_source() {
+	let ok_some: COption<Result<&'static str, ErrorOne>> = COption::Some(Ok("trans rights"));
+	let ok_none: COption<Result<&'static str, ErrorOne>> = COption::None;
+	let err: COption<Result<&'static str, ErrorOne>> = COption::Some(Err(ErrorOne::One));
+
+	let f = |source: Option<ErrorOne>| if source.is_some() { ErrorTwo::Three } else { ErrorTwo::Two };
+
+	assert!(ok_some.unwrap_or_err_with_source(f).is_ok());
+	assert!(matches!(ok_none.unwrap_or_err_with_source(f), Err(ErrorTwo::Two)));
+	assert!(matches!(err.unwrap_or_err_with_source(f), Err(ErrorTwo::Three)));
+}
+
+#[test]
+fn result_option_ext_over_coption_result_chained() {
+	let ok_some: COption<Result<&'static str, ErrorOne>> = COption::Some(Ok("trans rights"));
+	let ok_none: COption<Result<&'static str, ErrorOne>> = COption::None;
+	let err: COption<Result<&'static str, ErrorOne>> = COption::Some(Err(ErrorOne::One));
+
+	assert!(ok_some.unwrap_or_err_chained(ErrorTwo::Two).is_ok());
+	assert!(matches!(ok_none.unwrap_or_err_chained(ErrorTwo::Two), Err(ErrorTwo::Two)));
+	assert!(matches!(err.unwrap_or_err_chained(ErrorTwo::Two), Err(ErrorTwo::One(ErrorOne::One))));
+}
+
+#[test]
+fn result_option_ext_over_result_coption() {
+	let ok_some: Result<COption<&'static str>, ErrorOne> = Ok(COption::Some("trans rights"));
+	let ok_none: Result<COption<&'static str>, ErrorOne> = Ok(COption::None);
+	let err: Result<COption<&'static str>, ErrorOne> = Err(ErrorOne::One);
+
+	assert!(matches!(
+		ok_some.unwrap_or_map_err(ErrorTwo::Two, |_| ErrorTwo::Three),
+		Ok("trans rights")
+	));
+	assert!(matches!(ok_none.unwrap_or_map_err(ErrorTwo::Two, |_| ErrorTwo::Three), Err(ErrorTwo::Two)));
+	assert!(matches!(err.unwrap_or_map_err(ErrorTwo::Two, |_| ErrorTwo::Three), Err(ErrorTwo::Three)));
+}
+
+#[test]
+fn result_option_ext_over_result_coption_chained() {
+	let ok_some: Result<COption<&'static str>, ErrorOne> = Ok(COption::Some("trans rights"));
+	let ok_none: Result<COption<&'static str>, ErrorOne> = Ok(COption::None);
+	let err: Result<COption<&'static str>, ErrorOne> = Err(ErrorOne::One);
+
+	assert!(ok_some.unwrap_or_err_chained(ErrorTwo::Two).is_ok());
+	assert!(matches!(ok_none.unwrap_or_err_chained(ErrorTwo::Two), Err(ErrorTwo::Two)));
+	assert!(matches!(err.unwrap_or_err_chained(ErrorTwo::Two), Err(ErrorTwo::One(ErrorOne::One))));
+}
+
+#[test]
+fn async_option_ext_over_coption() {
+	async_io::block_on(async {
+		let value = COption::Some(69).async_map(|v| async move { v * 2 }).await;
+		assert_eq!(value, Some(138));
+
+		let value = COption::<u32>::None.async_map(|v| async move { v * 2 }).await;
+		assert_eq!(value, None);
+
+		let value = COption::Some(4).async_and_then(|v| async move { (v % 2 == 0).then_some(v / 2) }).await;
+		assert_eq!(value, Some(2));
+
+		let value = COption::<u32>::None.async_and_then(|v| async move { (v % 2 == 0).then_some(v / 2) }).await;
+		assert_eq!(value, None);
+
+		let value = COption::Some(4).async_filter(|v| async move { v % 2 == 0 }).await;
+		assert_eq!(value, Some(4));
+
+		let value = COption::Some(3).async_filter(|v| async move { v % 2 == 0 }).await;
+		assert_eq!(value, None);
+
+		let value = COption::<u32>::None.async_filter(|v| async move { v % 2 == 0 }).await;
+		assert_eq!(value, None);
+
+		let value = COption::Some(69).async_or_else(|| async { Some(0) }).await;
+		assert_eq!(value, Some(69));
+
+		let value = COption::<u32>::None.async_or_else(|| async { Some(69) }).await;
+		assert_eq!(value, Some(69));
+
+		let value = COption::Some(69).async_unwrap_or_else(|| async { 0 }).await;
+		assert_eq!(value, 69);
+
+		let value = COption::<u32>::None.async_unwrap_or_else(|| async { 69 }).await;
+		assert_eq!(value, 69);
+
+		let mut calls = 0;
+		let value = COption::Some(69).async_inspect(|_| async { calls += 1 }).await;
+		assert_eq!(value, COption::Some(69));
+		assert_eq!(calls, 1);
+
+		let value: COption<u32> = COption::<u32>::None.async_inspect(|_| async { calls += 1 }).await;
+		assert_eq!(value, COption::None);
+		assert_eq!(calls, 1);
+	});
+}
@@ -7,6 +7,13 @@ enum ErrorOne {
 enum ErrorTwo {
 	Two,
 	Three,
+	One(ErrorOne),
+}
+
+impl From<ErrorOne> for ErrorTwo {
+	fn from(err: ErrorOne) -> Self {
+		ErrorTwo::One(err)
+	}
 }
 
 fn result_ok_some() -> Result<Option<&'static str>, ErrorOne> {
@@ -82,6 +89,33 @@ fn unwrap_or_map_err() {
 	));
 }
 
+#[test]
+fn unwrap_or_err_chained() {
+	assert!(result_ok_some().unwrap_or_err_chained(ErrorTwo::Two).is_ok());
+	assert!(matches!(result_ok_none().unwrap_or_err_chained(ErrorTwo::Two), Err(ErrorTwo::Two)));
+	assert!(matches!(result_err().unwrap_or_err_chained(ErrorTwo::Two), Err(ErrorTwo::One(ErrorOne::One))));
+
+	assert!(option_some_ok().unwrap_or_err_chained(ErrorTwo::Two).is_ok());
+	assert!(matches!(
+		option_some_err().unwrap_or_err_chained(ErrorTwo::Two),
+		Err(ErrorTwo::One(ErrorOne::One))
+	));
+	assert!(matches!(option_none().unwrap_or_err_chained(ErrorTwo::Two), Err(ErrorTwo::Two)));
+}
+
+#[test]
+fn unwrap_or_err_with_source() {
+	let f = |source: Option<ErrorOne>| if source.is_some() { ErrorTwo::Three } else { ErrorTwo::Two };
+
+	assert!(result_ok_some().unwrap_or_err_with_source(f).is_ok());
+	assert!(matches!(result_ok_none().unwrap_or_err_with_source(f), Err(ErrorTwo::Two)));
+	assert!(matches!(result_err().unwrap_or_err_with_source(f), Err(ErrorTwo::Three)));
+
+	assert!(option_some_ok().unwrap_or_err_with_source(f).is_ok());
+	assert!(matches!(option_some_err().unwrap_or_err_with_source(f), Err(ErrorTwo::Three)));
+	assert!(matches!(option_none().unwrap_or_err_with_source(f), Err(ErrorTwo::Two)));
+}
+
 #[test]
 fn unwrap_or_else_map_err() {
 	assert!(result_ok_some()
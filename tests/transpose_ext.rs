@@ -0,0 +1,52 @@
+use composable_utils::{CollectResultExt, FlattenNoneExt, TransposeExt};
+
+// `Result`/`Option` already have an inherent `transpose` with the same behaviour, which always wins over a trait method of the
+// same name in dot-call position. These tests call through the trait explicitly (UFCS) so `TransposeExt`'s own impls are what's
+// actually being exercised, which matters when this trait is used as a bound in generic code over both shapes at once.
+
+#[test]
+fn transpose_result_to_option() {
+	let ok_some: Result<Option<&str>, &str> = Ok(Some("trans rights"));
+	let ok_none: Result<Option<&str>, &str> = Ok(None);
+	let err: Result<Option<&str>, &str> = Err("oh no");
+
+	assert_eq!(TransposeExt::transpose(ok_some), Some(Ok("trans rights")));
+	assert_eq!(TransposeExt::transpose(ok_none), None);
+	assert_eq!(TransposeExt::transpose(err), Some(Err("oh no")));
+}
+
+#[test]
+fn transpose_option_to_result() {
+	let some_ok: Option<Result<&str, &str>> = Some(Ok("trans rights"));
+	let some_err: Option<Result<&str, &str>> = Some(Err("oh no"));
+	let none: Option<Result<&str, &str>> = None;
+
+	assert_eq!(TransposeExt::transpose(some_ok), Ok(Some("trans rights")));
+	assert_eq!(TransposeExt::transpose(some_err), Err("oh no"));
+	assert_eq!(TransposeExt::transpose(none), Ok(None));
+}
+
+#[test]
+fn transpose_round_trips() {
+	let original: Result<Option<&str>, &str> = Ok(Some("trans rights"));
+	assert_eq!(TransposeExt::transpose(TransposeExt::transpose(original)), original);
+}
+
+#[test]
+fn flatten_none() {
+	assert_eq!(Some(Some("trans rights")).flatten_none(), Some("trans rights"));
+	assert_eq!(Some(None::<&str>).flatten_none(), None);
+	assert_eq!(None::<Option<&str>>.flatten_none(), None);
+}
+
+#[test]
+fn collect_result_skips_none_and_collects_ok() {
+	let items: Vec<Option<Result<u32, &str>>> = vec![Some(Ok(1)), None, Some(Ok(2)), None];
+	assert_eq!(items.into_iter().collect_result(), Ok(vec![1, 2]));
+}
+
+#[test]
+fn collect_result_short_circuits_on_err() {
+	let items: Vec<Option<Result<u32, &str>>> = vec![Some(Ok(1)), Some(Err("oh no")), Some(Ok(2))];
+	assert_eq!(items.into_iter().collect_result(), Err("oh no"));
+}